@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::key::PublicKey;
+
+#[derive(Serialize, Deserialize)]
+pub struct GetNonceParams {
+    pub address: PublicKey
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GetNonceResult {
+    pub nonce: u64
+}
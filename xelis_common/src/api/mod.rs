@@ -17,6 +17,11 @@ pub enum DataType {
     U64,
     U128,
     Hash,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
 }
 
 // This enum allows complex structures with multi depth if necessary
@@ -25,7 +30,7 @@ pub enum DataType {
 pub enum DataElement {
     // Value can be Optional to represent null in JSON
     Value(Option<DataValue>),
-    // For two next variants, we support up to 255 (u8::MAX) elements maximum
+    // Array and Fields lengths are CompactSize-encoded, so they aren't capped at 255 entries anymore
     Array(Vec<DataElement>),
     Fields(HashMap<DataValue, DataElement>)
 }
@@ -60,7 +65,7 @@ impl Serializer for DataElement {
         Ok(match reader.read_u8()? {
             0 => Self::Value(Option::<DataValue>::read(reader)?),
             1 => {
-                let size = reader.read_u8()?;
+                let size = read_collection_len(reader)?;
                 let mut values = Vec::new();
                 for _ in 0..size {
                     values.push(DataElement::read(reader)?)
@@ -68,7 +73,7 @@ impl Serializer for DataElement {
                 Self::Array(values)
             },
             2 => {
-                let size = reader.read_u8()?;
+                let size = read_collection_len(reader)?;
                 let mut fields = HashMap::new();
                 for _ in 0..size {
                     let key = DataValue::read(reader)?;
@@ -89,14 +94,14 @@ impl Serializer for DataElement {
             }
             Self::Array(values) => {
                 writer.write_u8(1);
-                writer.write_u8(values.len() as u8); // we accept up to 255 values
+                writer.write_varint(values.len() as u64);
                 for value in values {
-                    value.write(writer);    
+                    value.write(writer);
                 }
             }
             Self::Fields(fields) => {
                 writer.write_u8(2);
-                writer.write_u8(fields.len() as u8);
+                writer.write_varint(fields.len() as u64);
                 for (key, value) in fields {
                     key.write(writer);
                     value.write(writer);
@@ -106,6 +111,18 @@ impl Serializer for DataElement {
     }
 }
 
+// Reads a CompactSize-encoded collection length and rejects anything the reader couldn't
+// possibly back with real bytes, so a crafted huge length can't be used to force a big
+// allocation below: `read` itself stays allocation-free and grows the Vec/HashMap one push at a time.
+fn read_collection_len(reader: &mut Reader) -> Result<u64, ReaderError> {
+    let len = reader.read_varint()?;
+    if len > reader.total_remaining() as u64 {
+        return Err(ReaderError::InvalidValue)
+    }
+
+    Ok(len)
+}
+
 #[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Clone)]
 #[serde(untagged)]
 pub enum DataValue {
@@ -118,6 +135,11 @@ pub enum DataValue {
     U64(u64),
     U128(u128),
     Hash(Hash),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
 }
 
 impl DataValue {
@@ -130,7 +152,12 @@ impl DataValue {
             Self::U32(_) => DataType::U32,
             Self::U64(_) => DataType::U64,
             Self::U128(_) => DataType::U128,
-            Self::Hash(_) => DataType::Hash
+            Self::Hash(_) => DataType::Hash,
+            Self::I8(_) => DataType::I8,
+            Self::I16(_) => DataType::I16,
+            Self::I32(_) => DataType::I32,
+            Self::I64(_) => DataType::I64,
+            Self::I128(_) => DataType::I128
         }
     }
 }
@@ -146,6 +173,12 @@ impl Serializer for DataValue {
             5 => Self::U64(reader.read_u64()?),
             6 => Self::U128(reader.read_u128()?),
             7 => Self::Hash(reader.read_hash()?),
+            // new tags continue after the original tags 0-7, so old blobs still decode
+            8 => Self::I8(reader.read_i8()?),
+            9 => Self::I16(reader.read_i16()?),
+            10 => Self::I32(reader.read_i32()?),
+            11 => Self::I64(reader.read_i64()?),
+            12 => Self::I128(reader.read_i128()?),
             _ => return Err(ReaderError::InvalidValue)
         })
     }
@@ -183,6 +216,26 @@ impl Serializer for DataValue {
             Self::Hash(hash) => {
                 writer.write_u8(7);
                 writer.write_hash(hash);
+            },
+            Self::I8(value) => {
+                writer.write_u8(8);
+                writer.write_i8(*value);
+            },
+            Self::I16(value) => {
+                writer.write_u8(9);
+                writer.write_i16(*value);
+            },
+            Self::I32(value) => {
+                writer.write_u8(10);
+                writer.write_i32(value);
+            },
+            Self::I64(value) => {
+                writer.write_u8(11);
+                writer.write_i64(value);
+            },
+            Self::I128(value) => {
+                writer.write_u8(12);
+                writer.write_i128(value);
             }
         };
     }
@@ -206,3 +259,80 @@ pub struct DataHash<'a, T: Clone> {
     #[serde(flatten)]
     pub data: Cow<'a, T>
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array_of(len: usize) -> DataElement {
+        DataElement::Array((0..len).map(|_| DataElement::Value(Some(DataValue::Bool(true)))).collect())
+    }
+
+    #[test]
+    fn array_roundtrips_across_compactsize_boundaries() {
+        // 252/253 straddle the single-byte/0xFD boundary, 65535/65536 straddle the u16/u32 one
+        for len in [0, 1, 252, 253, 65535, 65536] {
+            let element = array_of(len);
+            let bytes = element.to_bytes();
+            let decoded = DataElement::from_bytes(&bytes).unwrap();
+
+            let DataElement::Array(values) = decoded else {
+                panic!("expected an Array to come back")
+            };
+            assert_eq!(values.len(), len);
+        }
+    }
+
+    #[test]
+    fn read_rejects_a_declared_length_longer_than_the_buffer() {
+        let mut writer = Writer::new();
+        writer.write_u8(1); // Array tag
+        writer.write_varint(1_000_000); // declared length, but no elements actually follow
+
+        let Err(err) = DataElement::from_bytes(writer.bytes()) else {
+            panic!("expected reading to fail")
+        };
+        assert_eq!(err, ReaderError::InvalidValue);
+    }
+
+    #[test]
+    fn signed_data_values_roundtrip_at_their_byte_order_sensitive_extremes() {
+        let values = vec![
+            DataValue::I8(i8::MIN),
+            DataValue::I8(i8::MAX),
+            DataValue::I8(-1),
+            DataValue::I16(i16::MIN),
+            DataValue::I16(i16::MAX),
+            DataValue::I32(i32::MIN),
+            DataValue::I32(i32::MAX),
+            DataValue::I64(i64::MIN),
+            DataValue::I64(i64::MAX),
+            DataValue::I128(i128::MIN),
+            DataValue::I128(i128::MAX),
+        ];
+
+        for value in values {
+            let element = DataElement::Value(Some(value.clone()));
+            let bytes = element.to_bytes();
+            let DataElement::Value(Some(decoded)) = DataElement::from_bytes(&bytes).unwrap() else {
+                panic!("expected a Value to come back")
+            };
+            assert!(decoded == value, "a signed DataValue didn't roundtrip");
+        }
+    }
+
+    #[test]
+    fn fields_roundtrip_across_compactsize_boundary() {
+        let mut fields = HashMap::new();
+        for i in 0..300u32 {
+            fields.insert(DataValue::U32(i), DataElement::Value(Some(DataValue::Bool(false))));
+        }
+        let element = DataElement::Fields(fields.clone());
+
+        let bytes = element.to_bytes();
+        let DataElement::Fields(decoded) = DataElement::from_bytes(&bytes).unwrap() else {
+            panic!("expected Fields to come back")
+        };
+        assert_eq!(decoded.len(), fields.len());
+    }
+}
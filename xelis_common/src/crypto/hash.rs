@@ -0,0 +1,41 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::serializer::{Reader, ReaderError, Serializer, Writer};
+
+pub const HASH_SIZE: usize = 32;
+
+/// 32-byte blake3 digest used for block, transaction and data identifiers.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Hash([u8; HASH_SIZE]);
+
+impl Hash {
+    pub fn new(bytes: [u8; HASH_SIZE]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; HASH_SIZE] {
+        &self.0
+    }
+}
+
+impl Serializer for Hash {
+    fn write(&self, writer: &mut Writer) {
+        writer.write_bytes(&self.0);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        let bytes = reader.read_bytes_32()?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Display for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+use bech32::{u5, Variant};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use ed25519_dalek::{Signer, Verifier, Signature as DalekSignature, SigningKey, VerifyingKey};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+use x25519_dalek::{PublicKey as XPublicKey, StaticSecret};
+
+use crate::serializer::{Reader, ReaderError, Serializer, Writer};
+
+pub const KEY_LENGTH: usize = 32;
+pub const SIGNATURE_LENGTH: usize = 64;
+
+/// Human-readable address prefix for mainnet wallets.
+pub const ADDRESS_PREFIX: &str = "xel";
+
+#[derive(Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct PublicKey(VerifyingKey);
+
+#[derive(Clone)]
+pub struct PrivateKey(SigningKey);
+
+pub struct Signature(DalekSignature);
+
+pub struct KeyPair {
+    private_key: PrivateKey,
+    public_key: PublicKey,
+}
+
+impl PublicKey {
+    pub fn as_bytes(&self) -> [u8; KEY_LENGTH] {
+        self.0.to_bytes()
+    }
+
+    pub fn verify(&self, data: &[u8], signature: &Signature) -> bool {
+        self.0.verify(data, &signature.0).is_ok()
+    }
+
+    /// Encodes the public key into the bech32 address format used across the wallet and daemon RPC.
+    pub fn to_address(&self) -> String {
+        let data = bech32::convert_bits(&self.as_bytes(), 8, 5, true)
+            .expect("32 bytes always regroup cleanly into 5-bit words")
+            .into_iter()
+            .map(|b| u5::try_from_u8(b).expect("convert_bits never emits a value above 31"))
+            .collect::<Vec<_>>();
+
+        bech32::encode(ADDRESS_PREFIX, data, Variant::Bech32)
+            .expect("ADDRESS_PREFIX is a valid bech32 human-readable part")
+    }
+
+    /// Converts the signing (Edwards) public key to its Montgomery form so it can be used
+    /// as an X25519 Diffie-Hellman key, e.g. to encrypt a transfer memo (see `crypto::memo`).
+    pub fn to_x25519(&self) -> XPublicKey {
+        let montgomery = CompressedEdwardsY(self.0.to_bytes())
+            .decompress()
+            .expect("a valid ed25519 public key always decompresses")
+            .to_montgomery();
+        XPublicKey::from(montgomery.to_bytes())
+    }
+}
+
+impl PrivateKey {
+    pub fn from_bytes(bytes: [u8; KEY_LENGTH]) -> Self {
+        Self(SigningKey::from_bytes(&bytes))
+    }
+
+    pub fn as_bytes(&self) -> [u8; KEY_LENGTH] {
+        self.0.to_bytes()
+    }
+
+    /// Derives the X25519 Diffie-Hellman secret matching `PublicKey::to_x25519`, following
+    /// the same seed-hashing approach libsodium uses to convert an Ed25519 key to X25519.
+    pub fn to_x25519(&self) -> StaticSecret {
+        let hash = Sha512::digest(self.0.to_bytes());
+        let mut scalar_bytes = [0u8; KEY_LENGTH];
+        scalar_bytes.copy_from_slice(&hash[..KEY_LENGTH]);
+        StaticSecret::from(scalar_bytes)
+    }
+}
+
+impl KeyPair {
+    /// Generates a fresh, random key pair.
+    pub fn new() -> Self {
+        let private_key = SigningKey::generate(&mut OsRng);
+        Self::from_private_key(PrivateKey(private_key))
+    }
+
+    pub fn from_private_key(private_key: PrivateKey) -> Self {
+        let public_key = PublicKey(private_key.0.verifying_key());
+        Self { private_key, public_key }
+    }
+
+    pub fn get_public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn get_private_key(&self) -> &PrivateKey {
+        &self.private_key
+    }
+
+    pub fn sign(&self, data: &[u8]) -> Signature {
+        Signature(self.private_key.0.sign(data))
+    }
+}
+
+impl Serializer for PublicKey {
+    fn write(&self, writer: &mut Writer) {
+        writer.write_bytes(&self.as_bytes());
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        let bytes = reader.read_bytes_32()?;
+        VerifyingKey::from_bytes(&bytes)
+            .map(Self)
+            .map_err(|_| ReaderError::InvalidValue)
+    }
+}
+
+impl Serializer for Signature {
+    fn write(&self, writer: &mut Writer) {
+        writer.write_bytes(&self.0.to_bytes());
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        let bytes = reader.read_bytes_64()?;
+        Ok(Self(DalekSignature::from_bytes(&bytes)))
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_address())
+    }
+}
@@ -0,0 +1,156 @@
+use chacha20poly1305::{aead::{Aead, KeyInit}, ChaCha20Poly1305, Nonce};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
+use x25519_dalek::{EphemeralSecret, PublicKey as XPublicKey, StaticSecret};
+
+use super::key::{PrivateKey, PublicKey, KEY_LENGTH};
+use crate::{
+    api::DataElement,
+    serializer::{Reader, ReaderError, Serializer, Writer}
+};
+
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+// Plaintext is capped so the ciphertext stays a small, fixed-capacity payload on the transfer
+pub const MEMO_MAX_PLAINTEXT_SIZE: usize = 256;
+
+/// A `DataElement` payload, shielded the way Zcash memos are: encrypted to the recipient's
+/// public key with an ephemeral key + shared-secret AEAD, so only they can read it.
+#[derive(Clone)]
+pub struct EncryptedMemo {
+    ephemeral_public_key: [u8; KEY_LENGTH],
+    nonce: [u8; NONCE_SIZE],
+    ciphertext: Vec<u8>
+}
+
+fn shared_key(shared_secret: &x25519_dalek::SharedSecret) -> chacha20poly1305::Key {
+    let digest = Sha256::digest(shared_secret.as_bytes());
+    chacha20poly1305::Key::clone_from_slice(&digest)
+}
+
+impl EncryptedMemo {
+    /// Encrypts `memo` so only the holder of `recipient`'s private key can decrypt it.
+    pub fn encrypt(memo: &DataElement, recipient: &PublicKey) -> Result<Self, MemoError> {
+        let plaintext = memo.to_bytes();
+        if plaintext.len() > MEMO_MAX_PLAINTEXT_SIZE {
+            return Err(MemoError::PlaintextTooLarge)
+        }
+
+        let ephemeral_secret = EphemeralSecret::random_from_rng(OsRng);
+        let ephemeral_public_key = XPublicKey::from(&ephemeral_secret);
+        let shared_secret = ephemeral_secret.diffie_hellman(&recipient.to_x25519());
+
+        let mut nonce_bytes = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut nonce_bytes);
+
+        let cipher = ChaCha20Poly1305::new(&shared_key(&shared_secret));
+        let ciphertext = cipher.encrypt(Nonce::from_slice(&nonce_bytes), plaintext.as_slice())
+            .map_err(|_| MemoError::EncryptionFailed)?;
+
+        Ok(Self {
+            ephemeral_public_key: ephemeral_public_key.to_bytes(),
+            nonce: nonce_bytes,
+            ciphertext
+        })
+    }
+
+    /// Trial-decrypts the memo with the owner's private key, returning `None` if it wasn't
+    /// addressed to them (wrong key, or the ciphertext was simply tampered with).
+    pub fn decrypt(&self, owner: &PrivateKey) -> Option<DataElement> {
+        let ephemeral_public_key = XPublicKey::from(self.ephemeral_public_key);
+        let shared_secret = owner.to_x25519().diffie_hellman(&ephemeral_public_key);
+
+        let cipher = ChaCha20Poly1305::new(&shared_key(&shared_secret));
+        let plaintext = cipher.decrypt(Nonce::from_slice(&self.nonce), self.ciphertext.as_slice()).ok()?;
+
+        DataElement::from_bytes(&plaintext).ok()
+    }
+}
+
+impl Serializer for EncryptedMemo {
+    fn write(&self, writer: &mut Writer) {
+        writer.write_bytes(&self.ephemeral_public_key);
+        writer.write_bytes(&self.nonce);
+        writer.write_varint(self.ciphertext.len() as u64);
+        writer.write_bytes(&self.ciphertext);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        let ephemeral_public_key = reader.read_bytes_32()?;
+        let nonce = reader.read_bytes::<NONCE_SIZE>()?;
+        let len = reader.read_varint()? as usize;
+        if len > MEMO_MAX_PLAINTEXT_SIZE + TAG_SIZE {
+            return Err(ReaderError::InvalidValue)
+        }
+        let ciphertext = reader.read_bytes_from_vec(len)?;
+
+        Ok(Self { ephemeral_public_key, nonce, ciphertext })
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum MemoError {
+    #[error("memo plaintext is larger than {} bytes", MEMO_MAX_PLAINTEXT_SIZE)]
+    PlaintextTooLarge,
+    #[error("failed to encrypt memo")]
+    EncryptionFailed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::DataValue, crypto::key::KeyPair};
+
+    fn text_memo(text: &str) -> DataElement {
+        DataElement::Value(Some(DataValue::String(text.to_string())))
+    }
+
+    #[test]
+    fn encrypt_then_decrypt_roundtrips() {
+        let recipient = KeyPair::new();
+        let memo = text_memo("invoice #42");
+
+        let encrypted = EncryptedMemo::encrypt(&memo, recipient.get_public_key()).unwrap();
+        let decrypted = encrypted.decrypt(recipient.get_private_key()).unwrap();
+
+        let DataElement::Value(Some(DataValue::String(text))) = decrypted else {
+            panic!("expected the decrypted memo to be the original string")
+        };
+        assert_eq!(text, "invoice #42");
+    }
+
+    #[test]
+    fn encrypt_rejects_oversized_plaintext() {
+        let recipient = KeyPair::new();
+        let oversized = text_memo(&"a".repeat(MEMO_MAX_PLAINTEXT_SIZE));
+
+        let Err(err) = EncryptedMemo::encrypt(&oversized, recipient.get_public_key()) else {
+            panic!("expected an oversized memo to be rejected")
+        };
+        assert_eq!(err, MemoError::PlaintextTooLarge);
+    }
+
+    #[test]
+    fn decrypt_with_the_wrong_key_returns_none() {
+        let recipient = KeyPair::new();
+        let someone_else = KeyPair::new();
+        let memo = text_memo("for your eyes only");
+
+        let encrypted = EncryptedMemo::encrypt(&memo, recipient.get_public_key()).unwrap();
+        assert!(encrypted.decrypt(someone_else.get_private_key()).is_none());
+    }
+
+    #[test]
+    fn serializer_roundtrips_an_encrypted_memo() {
+        let recipient = KeyPair::new();
+        let encrypted = EncryptedMemo::encrypt(&text_memo("hello"), recipient.get_public_key()).unwrap();
+
+        let bytes = encrypted.to_bytes();
+        let decoded = EncryptedMemo::from_bytes(&bytes).unwrap();
+
+        let DataElement::Value(Some(DataValue::String(text))) = decoded.decrypt(recipient.get_private_key()).unwrap() else {
+            panic!("expected the decrypted memo to be the original string")
+        };
+        assert_eq!(text, "hello");
+    }
+}
@@ -0,0 +1,250 @@
+use hmac::Hmac;
+use pbkdf2::pbkdf2;
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256, Sha512};
+use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
+
+use super::key::{KeyPair, PrivateKey};
+
+// BIP-39 English wordlist (2048 entries), embedded so wallets can restore
+// a KeyPair offline without any network access.
+const WORDLIST: &str = include_str!("wordlists/english.txt");
+const WORDLIST_SIZE: usize = 2048;
+const BITS_PER_WORD: usize = 11;
+const PBKDF2_ROUNDS: u32 = 2048;
+const SEED_LEN: usize = 64;
+
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum MnemonicError {
+    #[error("entropy must be 128, 160, 192, 224 or 256 bits long")]
+    InvalidEntropyLength,
+    #[error("mnemonic phrase must contain 12, 15, 18, 21 or 24 words")]
+    InvalidWordCount,
+    #[error("'{0}' is not part of the BIP-39 English wordlist")]
+    UnknownWord(String),
+    #[error("checksum doesn't match, mnemonic phrase is invalid")]
+    InvalidChecksum,
+}
+
+fn wordlist() -> Vec<&'static str> {
+    let words: Vec<&'static str> = WORDLIST.lines().collect();
+    assert_eq!(words.len(), WORDLIST_SIZE, "embedded wordlist must contain exactly {} words", WORDLIST_SIZE);
+    words
+}
+
+/// Amount of entropy to generate a mnemonic from, named after the resulting word count.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MnemonicStrength {
+    Words12,
+    Words15,
+    Words18,
+    Words21,
+    Words24
+}
+
+impl MnemonicStrength {
+    fn entropy_bytes(self) -> usize {
+        match self {
+            Self::Words12 => 16,
+            Self::Words15 => 20,
+            Self::Words18 => 24,
+            Self::Words21 => 28,
+            Self::Words24 => 32
+        }
+    }
+}
+
+impl Default for MnemonicStrength {
+    fn default() -> Self {
+        Self::Words24
+    }
+}
+
+// Splits entropy + its checksum into BITS_PER_WORD-bit groups and maps each to a wordlist index.
+fn entropy_to_mnemonic(entropy: &[u8]) -> Result<String, MnemonicError> {
+    let ent_bits = entropy.len() * 8;
+    if ![128, 160, 192, 224, 256].contains(&ent_bits) {
+        return Err(MnemonicError::InvalidEntropyLength)
+    }
+
+    let checksum_bits = ent_bits / 32;
+    let hash = Sha256::digest(entropy);
+
+    // Concatenate entropy bits with the leading `checksum_bits` bits of SHA256(entropy)
+    let mut bits = Vec::with_capacity(ent_bits + checksum_bits);
+    for byte in entropy {
+        for i in (0..8).rev() {
+            bits.push((byte >> i) & 1 == 1);
+        }
+    }
+    for i in 0..checksum_bits {
+        let byte = hash[i / 8];
+        bits.push((byte >> (7 - (i % 8))) & 1 == 1);
+    }
+
+    let words = wordlist();
+    let mut mnemonic = Vec::with_capacity(bits.len() / BITS_PER_WORD);
+    for chunk in bits.chunks(BITS_PER_WORD) {
+        let mut index = 0usize;
+        for bit in chunk {
+            index = (index << 1) | (*bit as usize);
+        }
+        mnemonic.push(words[index]);
+    }
+
+    Ok(mnemonic.join(" "))
+}
+
+// Reverses entropy_to_mnemonic and verifies the embedded checksum.
+fn mnemonic_to_entropy(mnemonic: &str) -> Result<Vec<u8>, MnemonicError> {
+    let words = wordlist();
+    let phrase: Vec<&str> = mnemonic.split_whitespace().collect();
+    if ![12, 15, 18, 21, 24].contains(&phrase.len()) {
+        return Err(MnemonicError::InvalidWordCount)
+    }
+
+    let mut bits = Vec::with_capacity(phrase.len() * BITS_PER_WORD);
+    for word in &phrase {
+        let index = words.iter().position(|w| w == word)
+            .ok_or_else(|| MnemonicError::UnknownWord(word.to_string()))?;
+        for i in (0..BITS_PER_WORD).rev() {
+            bits.push((index >> i) & 1 == 1);
+        }
+    }
+
+    let ent_bits = bits.len() * 32 / 33;
+    let checksum_bits = bits.len() - ent_bits;
+
+    let mut entropy = vec![0u8; ent_bits / 8];
+    for (i, byte) in entropy.iter_mut().enumerate() {
+        for b in 0..8 {
+            *byte = (*byte << 1) | (bits[i * 8 + b] as u8);
+        }
+    }
+
+    let hash = Sha256::digest(&entropy);
+    for i in 0..checksum_bits {
+        let expected = (hash[i / 8] >> (7 - (i % 8))) & 1 == 1;
+        if expected != bits[ent_bits + i] {
+            return Err(MnemonicError::InvalidChecksum)
+        }
+    }
+
+    Ok(entropy)
+}
+
+// PBKDF2-HMAC-SHA512(password = mnemonic, salt = "mnemonic" + passphrase, iterations = 2048, dklen = 64)
+fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; SEED_LEN] {
+    let normalized_mnemonic: String = mnemonic.nfkd().collect();
+    let salt = format!("mnemonic{}", passphrase.nfkd().collect::<String>());
+
+    let mut seed = [0u8; SEED_LEN];
+    pbkdf2::<Hmac<Sha512>>(normalized_mnemonic.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed)
+        .expect("PBKDF2-HMAC-SHA512 with a 64 byte output never fails");
+
+    seed
+}
+
+/// Generates a new BIP-39 mnemonic of the requested strength (128-256 bits of entropy,
+/// i.e. 12 to 24 words).
+pub fn generate_mnemonic(strength: MnemonicStrength) -> String {
+    let mut entropy = vec![0u8; strength.entropy_bytes()];
+    OsRng.fill_bytes(&mut entropy);
+    entropy_to_mnemonic(&entropy).expect("MnemonicStrength always maps to a valid entropy length")
+}
+
+impl KeyPair {
+    /// Deterministically restores the KeyPair backed up as a BIP-39 mnemonic phrase.
+    /// The optional passphrase acts as an extra, user-chosen 25th word.
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, MnemonicError> {
+        mnemonic_to_entropy(mnemonic)?;
+
+        let seed = mnemonic_to_seed(mnemonic, passphrase);
+        let mut private_key_bytes = [0u8; 32];
+        private_key_bytes.copy_from_slice(&seed[..32]);
+
+        Ok(Self::from_private_key(PrivateKey::from_bytes(private_key_bytes)))
+    }
+
+    /// Generates a brand new KeyPair together with the mnemonic phrase that can recover it later on.
+    pub fn generate_with_mnemonic(passphrase: &str, strength: MnemonicStrength) -> (Self, String) {
+        let mnemonic = generate_mnemonic(strength);
+        let keypair = Self::from_mnemonic(&mnemonic, passphrase)
+            .expect("a freshly generated mnemonic is always valid");
+        (keypair, mnemonic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STRENGTHS: [MnemonicStrength; 5] = [
+        MnemonicStrength::Words12,
+        MnemonicStrength::Words15,
+        MnemonicStrength::Words18,
+        MnemonicStrength::Words21,
+        MnemonicStrength::Words24
+    ];
+
+    #[test]
+    fn entropy_roundtrips_through_a_mnemonic_at_every_supported_length() {
+        for strength in STRENGTHS {
+            // Deterministic, length-dependent entropy so the test doesn't depend on an RNG
+            let entropy: Vec<u8> = (0..strength.entropy_bytes()).map(|i| i as u8).collect();
+
+            let mnemonic = entropy_to_mnemonic(&entropy).unwrap();
+            assert_eq!(mnemonic.split_whitespace().count(), strength.entropy_bytes() * 8 / 11 + 1);
+
+            let recovered = mnemonic_to_entropy(&mnemonic).unwrap();
+            assert_eq!(recovered, entropy);
+        }
+    }
+
+    #[test]
+    fn all_zero_128_bit_entropy_matches_the_official_bip39_test_vector() {
+        let entropy = [0u8; 16];
+        let mnemonic = entropy_to_mnemonic(&entropy).unwrap();
+        assert_eq!(
+            mnemonic,
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+    }
+
+    #[test]
+    fn mnemonic_to_entropy_rejects_an_unknown_word() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon notaword";
+        assert_eq!(mnemonic_to_entropy(mnemonic).unwrap_err(), MnemonicError::UnknownWord("notaword".to_string()));
+    }
+
+    #[test]
+    fn mnemonic_to_entropy_rejects_a_bad_checksum() {
+        // Swapping the last word for another valid one keeps the word count right but breaks the checksum
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon zoo";
+        assert_eq!(mnemonic_to_entropy(mnemonic).unwrap_err(), MnemonicError::InvalidChecksum);
+    }
+
+    #[test]
+    fn mnemonic_to_entropy_rejects_the_wrong_word_count() {
+        assert_eq!(mnemonic_to_entropy("abandon abandon abandon").unwrap_err(), MnemonicError::InvalidWordCount);
+    }
+
+    #[test]
+    fn keypair_roundtrips_through_generate_with_mnemonic_and_from_mnemonic() {
+        let (keypair, mnemonic) = KeyPair::generate_with_mnemonic("", MnemonicStrength::Words12);
+        let restored = KeyPair::from_mnemonic(&mnemonic, "").unwrap();
+
+        assert!(keypair.get_public_key() == restored.get_public_key());
+    }
+
+    #[test]
+    fn a_passphrase_changes_the_derived_keypair() {
+        let (_, mnemonic) = KeyPair::generate_with_mnemonic("", MnemonicStrength::Words12);
+
+        let without_passphrase = KeyPair::from_mnemonic(&mnemonic, "").unwrap();
+        let with_passphrase = KeyPair::from_mnemonic(&mnemonic, "extra word").unwrap();
+
+        assert!(without_passphrase.get_public_key() != with_passphrase.get_public_key());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod hash;
+pub mod key;
+pub mod memo;
+pub mod mnemonic;
+pub mod vanity;
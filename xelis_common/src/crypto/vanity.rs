@@ -0,0 +1,67 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex
+};
+
+use thiserror::Error;
+
+use super::key::{KeyPair, ADDRESS_PREFIX};
+
+// bech32 charset: the only characters that can ever show up after the "xel1" human-readable part
+const ADDRESS_ALPHABET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum VanityError {
+    #[error("prefix contains a character outside the bech32 address alphabet")]
+    InvalidPrefix,
+    #[error("no matching address found after {0} attempts")]
+    Timeout(usize)
+}
+
+impl KeyPair {
+    /// Repeatedly samples key pairs across `thread_count` threads until one whose address
+    /// begins with `prefix` (right after the "xel1" human-readable part) is found, or
+    /// `max_attempts` (shared across all threads) is exhausted. `thread_count` is clamped
+    /// to at least 1.
+    pub fn generate_with_prefix(prefix: &str, max_attempts: usize, thread_count: usize) -> Result<Self, VanityError> {
+        let prefix = prefix.to_lowercase();
+        if !prefix.chars().all(|c| ADDRESS_ALPHABET.contains(c)) {
+            return Err(VanityError::InvalidPrefix)
+        }
+
+        let worker_count = thread_count.max(1);
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let found: Arc<Mutex<Option<KeyPair>>> = Arc::new(Mutex::new(None));
+
+        std::thread::scope(|scope| {
+            for _ in 0..worker_count {
+                let prefix = prefix.as_str();
+                let attempts = Arc::clone(&attempts);
+                let stop = Arc::clone(&stop);
+                let found = Arc::clone(&found);
+
+                scope.spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        if attempts.fetch_add(1, Ordering::Relaxed) >= max_attempts {
+                            stop.store(true, Ordering::Relaxed);
+                            return
+                        }
+
+                        let candidate = KeyPair::new();
+                        let address = candidate.get_public_key().to_address();
+                        let data_part = &address[ADDRESS_PREFIX.len() + 1..];
+                        if data_part.starts_with(prefix) {
+                            *found.lock().expect("vanity search mutex poisoned") = Some(candidate);
+                            stop.store(true, Ordering::Relaxed);
+                            return
+                        }
+                    }
+                });
+            }
+        });
+
+        found.lock().expect("vanity search mutex poisoned").take()
+            .ok_or(VanityError::Timeout(max_attempts))
+    }
+}
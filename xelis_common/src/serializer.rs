@@ -0,0 +1,271 @@
+use crate::crypto::hash::{Hash, HASH_SIZE};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ReaderError {
+    InvalidValue,
+    InvalidSize,
+    ErrorTryInto
+}
+
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    // How many bytes are left to read
+    pub fn total_remaining(&self) -> usize {
+        self.size() - self.offset
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, ReaderError> {
+        Ok(self.read_u8()? != 0)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, ReaderError> {
+        let byte = *self.bytes.get(self.offset).ok_or(ReaderError::InvalidSize)?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, ReaderError> {
+        let bytes = self.read_bytes::<2>()?;
+        Ok(u16::from_be_bytes(bytes))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, ReaderError> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, ReaderError> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    pub fn read_u128(&mut self) -> Result<u128, ReaderError> {
+        let bytes = self.read_bytes::<16>()?;
+        Ok(u128::from_be_bytes(bytes))
+    }
+
+    // Signed integers are two's-complement big-endian, same byte order as the unsigned helpers above
+    pub fn read_i8(&mut self) -> Result<i8, ReaderError> {
+        Ok(self.read_u8()? as i8)
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16, ReaderError> {
+        let bytes = self.read_bytes::<2>()?;
+        Ok(i16::from_be_bytes(bytes))
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32, ReaderError> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(i32::from_be_bytes(bytes))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, ReaderError> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(i64::from_be_bytes(bytes))
+    }
+
+    pub fn read_i128(&mut self) -> Result<i128, ReaderError> {
+        let bytes = self.read_bytes::<16>()?;
+        Ok(i128::from_be_bytes(bytes))
+    }
+
+    // Read a CompactSize-encoded length: <0xFD single byte, 0xFD + u16, 0xFE + u32, 0xFF + u64
+    pub fn read_varint(&mut self) -> Result<u64, ReaderError> {
+        Ok(match self.read_u8()? {
+            0xFD => self.read_u16()? as u64,
+            0xFE => self.read_u32()? as u64,
+            0xFF => self.read_u64()?,
+            value => value as u64
+        })
+    }
+
+    pub fn read_string(&mut self) -> Result<String, ReaderError> {
+        let len = self.read_varint()? as usize;
+        let bytes = self.read_bytes_from_vec(len)?;
+        String::from_utf8(bytes).map_err(|_| ReaderError::InvalidValue)
+    }
+
+    pub fn read_hash(&mut self) -> Result<Hash, ReaderError> {
+        let bytes = self.read_bytes_32()?;
+        Ok(Hash::new(bytes))
+    }
+
+    pub fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], ReaderError> {
+        if self.total_remaining() < N {
+            return Err(ReaderError::InvalidSize)
+        }
+
+        let mut array = [0u8; N];
+        array.copy_from_slice(&self.bytes[self.offset..self.offset + N]);
+        self.offset += N;
+        Ok(array)
+    }
+
+    pub fn read_bytes_32(&mut self) -> Result<[u8; HASH_SIZE], ReaderError> {
+        self.read_bytes::<HASH_SIZE>()
+    }
+
+    pub fn read_bytes_64(&mut self) -> Result<[u8; 64], ReaderError> {
+        self.read_bytes::<64>()
+    }
+
+    // Unlike read_bytes, this allocates a Vec without pre-reserving `len`,
+    // so an attacker-controlled length cannot be used to trigger an OOM before we even start reading.
+    pub fn read_bytes_from_vec(&mut self, len: usize) -> Result<Vec<u8>, ReaderError> {
+        if self.total_remaining() < len {
+            return Err(ReaderError::InvalidSize)
+        }
+
+        let mut bytes = Vec::new();
+        for _ in 0..len {
+            bytes.push(self.read_u8()?);
+        }
+        Ok(bytes)
+    }
+}
+
+pub struct Writer {
+    bytes: Vec<u8>
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        Self {
+            bytes: Vec::new()
+        }
+    }
+
+    pub fn total_write(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_u8(value as u8);
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    pub fn write_u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_u32(&mut self, value: &u32) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_u64(&mut self, value: &u64) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_u128(&mut self, value: &u128) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    // Signed integers are two's-complement big-endian, same byte order as the unsigned helpers above
+    pub fn write_i8(&mut self, value: i8) {
+        self.write_u8(value as u8);
+    }
+
+    pub fn write_i16(&mut self, value: i16) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_i32(&mut self, value: &i32) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_i64(&mut self, value: &i64) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_i128(&mut self, value: &i128) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    // CompactSize-encode a collection length so we aren't capped at 255 entries (see DataElement)
+    pub fn write_varint(&mut self, value: u64) {
+        if value < 0xFD {
+            self.write_u8(value as u8);
+        } else if value <= u16::MAX as u64 {
+            self.write_u8(0xFD);
+            self.write_u16(value as u16);
+        } else if value <= u32::MAX as u64 {
+            self.write_u8(0xFE);
+            self.write_u32(&(value as u32));
+        } else {
+            self.write_u8(0xFF);
+            self.write_u64(&value);
+        }
+    }
+
+    pub fn write_string(&mut self, value: &str) {
+        self.write_varint(value.len() as u64);
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    pub fn write_hash(&mut self, hash: &Hash) {
+        self.write_bytes(hash.as_bytes());
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+}
+
+pub trait Serializer: Sized {
+    fn write(&self, writer: &mut Writer);
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError>;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer::new();
+        self.write(&mut writer);
+        writer.bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ReaderError> {
+        let mut reader = Reader::new(bytes);
+        Self::read(&mut reader)
+    }
+}
+
+impl<T: Serializer> Serializer for Option<T> {
+    fn write(&self, writer: &mut Writer) {
+        match self {
+            Some(value) => {
+                writer.write_bool(true);
+                value.write(writer);
+            },
+            None => writer.write_bool(false)
+        }
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        Ok(if reader.read_bool()? {
+            Some(T::read(reader)?)
+        } else {
+            None
+        })
+    }
+}
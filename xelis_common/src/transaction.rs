@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use crate::{
+    crypto::{hash::Hash, key::{PublicKey, Signature}, memo::EncryptedMemo},
+    serializer::{Reader, ReaderError, Serializer, Writer}
+};
+
+#[derive(Clone)]
+pub struct Transfer {
+    pub to: PublicKey,
+    pub asset: Hash,
+    pub amount: u64,
+    // Private note (invoice id, reference...) only `to` can decrypt, see `EncryptedMemo`
+    pub memo: Option<EncryptedMemo>
+}
+
+#[derive(Clone)]
+pub struct CallContractPayload {
+    pub contract: Hash,
+    pub assets: HashMap<Hash, u64>,
+    pub params: Vec<u8>
+}
+
+#[derive(Clone)]
+pub enum TransactionType {
+    Burn(Hash, u64),
+    CallContract(CallContractPayload),
+    Transfer(Vec<Transfer>),
+    DeployContract(Vec<u8>)
+}
+
+pub struct Transaction {
+    pub owner: PublicKey,
+    pub data: TransactionType,
+    pub fee: u64,
+    pub nonce: u64,
+    pub signature: Signature
+}
+
+impl Transaction {
+    pub fn new(owner: PublicKey, data: TransactionType, fee: u64, nonce: u64, signature: Signature) -> Self {
+        Self { owner, data, fee, nonce, signature }
+    }
+}
+
+impl Serializer for Transfer {
+    fn write(&self, writer: &mut Writer) {
+        self.to.write(writer);
+        self.asset.write(writer);
+        writer.write_u64(&self.amount);
+        self.memo.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        Ok(Self {
+            to: PublicKey::read(reader)?,
+            asset: Hash::read(reader)?,
+            amount: reader.read_u64()?,
+            memo: Option::<EncryptedMemo>::read(reader)?
+        })
+    }
+}
+
+impl Serializer for CallContractPayload {
+    fn write(&self, writer: &mut Writer) {
+        self.contract.write(writer);
+        writer.write_varint(self.assets.len() as u64);
+        for (asset, amount) in &self.assets {
+            asset.write(writer);
+            writer.write_u64(amount);
+        }
+        writer.write_varint(self.params.len() as u64);
+        writer.write_bytes(&self.params);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        let contract = Hash::read(reader)?;
+
+        let assets_len = reader.read_varint()? as usize;
+        let mut assets = HashMap::new();
+        for _ in 0..assets_len {
+            assets.insert(Hash::read(reader)?, reader.read_u64()?);
+        }
+
+        let params_len = reader.read_varint()? as usize;
+        let params = reader.read_bytes_from_vec(params_len)?;
+
+        Ok(Self { contract, assets, params })
+    }
+}
+
+impl Serializer for TransactionType {
+    fn write(&self, writer: &mut Writer) {
+        match self {
+            Self::Burn(asset, amount) => {
+                writer.write_u8(0);
+                asset.write(writer);
+                writer.write_u64(amount);
+            },
+            Self::CallContract(payload) => {
+                writer.write_u8(1);
+                payload.write(writer);
+            },
+            Self::Transfer(transfers) => {
+                writer.write_u8(2);
+                writer.write_varint(transfers.len() as u64);
+                for transfer in transfers {
+                    transfer.write(writer);
+                }
+            },
+            Self::DeployContract(bytecode) => {
+                writer.write_u8(3);
+                writer.write_varint(bytecode.len() as u64);
+                writer.write_bytes(bytecode);
+            }
+        }
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, ReaderError> {
+        Ok(match reader.read_u8()? {
+            0 => Self::Burn(Hash::read(reader)?, reader.read_u64()?),
+            1 => Self::CallContract(CallContractPayload::read(reader)?),
+            2 => {
+                let len = reader.read_varint()? as usize;
+                let mut transfers = Vec::new();
+                for _ in 0..len {
+                    transfers.push(Transfer::read(reader)?);
+                }
+                Self::Transfer(transfers)
+            },
+            3 => {
+                let len = reader.read_varint()? as usize;
+                Self::DeployContract(reader.read_bytes_from_vec(len)?)
+            },
+            _ => return Err(ReaderError::InvalidValue)
+        })
+    }
+}
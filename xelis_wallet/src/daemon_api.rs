@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use xelis_common::{
+    api::daemon::{GetNonceParams, GetNonceResult},
+    crypto::key::PublicKey
+};
+
+use crate::{transaction_builder::NonceProvider, wallet::WalletError};
+
+// Thin JSON-RPC client used by the wallet to query the daemon it is connected to.
+pub struct DaemonAPI {
+    client: reqwest::Client,
+    daemon_address: String
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String
+}
+
+// JSON-RPC 2.0 response envelope: a spec-compliant daemon wraps the actual payload
+// in `result`, and only ever sets `error` when the call failed.
+#[derive(Deserialize)]
+struct JsonRpcResponse<R> {
+    result: Option<R>,
+    error: Option<JsonRpcError>
+}
+
+impl DaemonAPI {
+    pub fn new(daemon_address: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            daemon_address
+        }
+    }
+
+    async fn call<P: serde::Serialize, R: serde::de::DeserializeOwned>(&self, method: &str, params: &P) -> Result<R, WalletError> {
+        let response = self.client.post(&self.daemon_address)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params
+            }))
+            .send()
+            .await
+            .map_err(|_| WalletError::DaemonRequestFailed)?;
+
+        let envelope: JsonRpcResponse<R> = response.json().await
+            .map_err(|_| WalletError::DaemonRequestFailed)?;
+
+        if let Some(error) = envelope.error {
+            return Err(WalletError::DaemonRpcError(error.code, error.message))
+        }
+
+        envelope.result.ok_or(WalletError::DaemonRequestFailed)
+    }
+}
+
+#[async_trait]
+impl NonceProvider for DaemonAPI {
+    async fn get_nonce(&self, owner: &PublicKey) -> Result<u64, WalletError> {
+        let result: GetNonceResult = self.call("get_nonce", &GetNonceParams { address: owner.clone() }).await?;
+        Ok(result.nonce)
+    }
+}
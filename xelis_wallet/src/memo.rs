@@ -0,0 +1,7 @@
+use xelis_common::{api::DataElement, crypto::key::PrivateKey, transaction::Transfer};
+
+/// Trial-decrypts the memo attached to an incoming transfer with the wallet's own private key.
+/// Returns `None` when the transfer carries no memo, or it wasn't actually addressed to us.
+pub fn decrypt_incoming_memo(transfer: &Transfer, owner: &PrivateKey) -> Option<DataElement> {
+    transfer.memo.as_ref()?.decrypt(owner)
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use async_trait::async_trait;
 use xelis_common::{
     transaction::{Transaction, TransactionType},
     globals::calculate_tx_fee,
@@ -9,18 +10,27 @@ use xelis_common::{
 
 use crate::wallet::WalletError;
 
+// Resolves the next usable nonce for an account so a built transaction can't be replayed
+// or conflict with another one already in flight for the same owner.
+#[async_trait]
+pub trait NonceProvider {
+    async fn get_nonce(&self, owner: &PublicKey) -> Result<u64, WalletError>;
+}
+
 pub struct TransactionBuilder {
     owner: PublicKey,
     data: TransactionType,
-    fee_multiplier: f64
+    fee_multiplier: f64,
+    nonce: u64
 }
 
 impl TransactionBuilder {
-    pub fn new(owner: PublicKey, data: TransactionType, fee_multiplier: f64) -> Self {
+    pub fn new(owner: PublicKey, data: TransactionType, fee_multiplier: f64, nonce: u64) -> Self {
         Self {
             owner,
             data,
-            fee_multiplier
+            fee_multiplier,
+            nonce
         }
     }
 
@@ -28,6 +38,7 @@ impl TransactionBuilder {
         let mut writer = Writer::new();
         self.owner.write(&mut writer);
         self.data.write(&mut writer);
+        writer.write_u64(&self.nonce);
         writer
     }
 
@@ -82,14 +93,27 @@ impl TransactionBuilder {
             }
         }
 
+        // self.serialize() already wrote the nonce, so it is part of the signed payload
+        // and the daemon can reject a transaction replayed with a stale or duplicate nonce
         let mut writer = self.serialize();
         let fee = self.estimate_fees_internal(&writer);
         writer.write_u64(&fee);
 
-        let nonce = 0; // TODO
         let signature = keypair.sign(&writer.bytes());
-        let tx = Transaction::new(self.owner, self.data, fee, nonce, signature);
+        let tx = Transaction::new(self.owner, self.data, fee, self.nonce, signature);
 
         Ok(tx)
     }
+
+    // Overrides the nonce set in `new` with one the caller already knows (e.g. tracked locally)
+    pub fn build_with_nonce(mut self, nonce: u64, keypair: &KeyPair) -> Result<Transaction, WalletError> {
+        self.nonce = nonce;
+        self.build(keypair)
+    }
+
+    // Resolves the nonce from a `NonceProvider` (typically backed by the daemon RPC) before building
+    pub async fn build_with_provider<P: NonceProvider + Sync>(mut self, provider: &P, keypair: &KeyPair) -> Result<Transaction, WalletError> {
+        self.nonce = provider.get_nonce(&self.owner).await?;
+        self.build(keypair)
+    }
 }
\ No newline at end of file
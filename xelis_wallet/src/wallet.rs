@@ -0,0 +1,45 @@
+use thiserror::Error;
+use xelis_common::crypto::{key::KeyPair, mnemonic::MnemonicError, vanity::VanityError};
+
+#[derive(Error, Debug)]
+pub enum WalletError {
+    #[error("keypair doesn't match the transaction owner")]
+    InvalidKeyPair,
+    #[error("expected at least one transfer")]
+    ExpectedOneTx,
+    #[error("a transaction can't have the owner as receiver")]
+    TxOwnerIsReceiver,
+    #[error("invalid mnemonic phrase: {0}")]
+    InvalidMnemonic(#[from] MnemonicError),
+    #[error("request to the daemon RPC failed")]
+    DaemonRequestFailed,
+    #[error("daemon RPC returned an error ({0}): {1}")]
+    DaemonRpcError(i64, String),
+    #[error("prefix contains a character outside the bech32 address alphabet")]
+    InvalidVanityPrefix,
+    #[error("no matching vanity address found after {0} attempts")]
+    VanityTimeout(usize),
+}
+
+impl From<VanityError> for WalletError {
+    fn from(error: VanityError) -> Self {
+        match error {
+            VanityError::InvalidPrefix => Self::InvalidVanityPrefix,
+            VanityError::Timeout(attempts) => Self::VanityTimeout(attempts),
+        }
+    }
+}
+
+/// Mints a `KeyPair` whose address begins with `prefix`, labeling it for display without
+/// changing any on-chain format. The search runs across `thread_count` threads (pass 0 to
+/// use every available CPU core) and gives up with `WalletError::VanityTimeout` once
+/// `max_attempts` is exhausted.
+pub fn generate_vanity_keypair(prefix: &str, max_attempts: usize, thread_count: usize) -> Result<KeyPair, WalletError> {
+    let thread_count = if thread_count == 0 {
+        std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    } else {
+        thread_count
+    };
+
+    Ok(KeyPair::generate_with_prefix(prefix, max_attempts, thread_count)?)
+}